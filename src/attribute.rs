@@ -0,0 +1,84 @@
+use crate::*;
+
+/// An LLVM attribute, either a well-known enum attribute (e.g. `noalias`) or an arbitrary
+/// string attribute (e.g. a target-specific key/value pair). Attributes are uniqued and owned by
+/// the [`Context`] that created them, so this borrows it for as long as the attribute lives.
+pub struct Attribute<'a>(NonNull<llvm::LLVMOpaqueAttributeRef>, PhantomData<&'a Context>);
+
+llvm_inner_impl!(Attribute<'a>, llvm::LLVMOpaqueAttributeRef);
+
+impl<'a> Attribute<'a> {
+    pub(crate) fn from_inner(ptr: *mut llvm::LLVMOpaqueAttributeRef) -> Result<Attribute<'a>, Error> {
+        Ok(Attribute(wrap_inner(ptr)?, PhantomData))
+    }
+
+    /// Create an enum attribute, e.g. looked up via [`Attribute::enum_kind_for_name`]
+    pub fn new_enum(context: &'a Context, kind_id: u32, value: u64) -> Result<Attribute<'a>, Error> {
+        let ptr = unsafe {
+            llvm::core::LLVMCreateEnumAttribute(context.llvm_inner(), kind_id, value)
+        };
+
+        Ok(Attribute(wrap_inner(ptr)?, PhantomData))
+    }
+
+    /// Create a string attribute from an arbitrary key/value pair
+    pub fn new_string(context: &'a Context, key: impl AsRef<str>, value: impl AsRef<str>) -> Result<Attribute<'a>, Error> {
+        let key = key.as_ref();
+        let value = value.as_ref();
+
+        let ptr = unsafe {
+            llvm::core::LLVMCreateStringAttribute(
+                context.llvm_inner(),
+                key.as_ptr() as *const c_char,
+                key.len() as c_uint,
+                value.as_ptr() as *const c_char,
+                value.len() as c_uint,
+            )
+        };
+
+        Ok(Attribute(wrap_inner(ptr)?, PhantomData))
+    }
+
+    /// Whether this is a string attribute rather than an enum attribute
+    pub fn is_string(&self) -> bool {
+        unsafe { llvm::core::LLVMIsStringAttribute(self.llvm_inner()) == 1 }
+    }
+
+    /// Look up the `kind_id` for a well-known enum attribute by name, e.g. `"noalias"` or
+    /// `"sret"`, for use with [`Attribute::new_enum`]
+    pub fn enum_kind_for_name(name: impl AsRef<str>) -> Option<u32> {
+        let name = name.as_ref();
+        let kind_id = unsafe {
+            llvm::core::LLVMGetEnumAttributeKindForName(name.as_ptr() as *const c_char, name.len())
+        };
+
+        if kind_id == 0 {
+            None
+        } else {
+            Some(kind_id)
+        }
+    }
+}
+
+/// Where an [`Attribute`] applies on a function: its return value, a specific argument, or the
+/// function itself. Maps to LLVM's attribute index (`0` for the return value, `1 + i` for
+/// argument `i`, and `!0` for the function).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributePlace {
+    /// The function's return value
+    ReturnValue,
+    /// The `i`th argument of the function
+    Argument(u32),
+    /// The function itself
+    Function,
+}
+
+impl AttributePlace {
+    pub(crate) fn as_index(&self) -> llvm::LLVMAttributeIndex {
+        match *self {
+            AttributePlace::ReturnValue => 0,
+            AttributePlace::Argument(i) => 1 + i,
+            AttributePlace::Function => !0,
+        }
+    }
+}
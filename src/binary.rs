@@ -0,0 +1,260 @@
+use crate::*;
+
+/// A binary file recognized by LLVM's object reader: an object file, archive, or universal
+/// binary, wrapping `LLVMBinaryRef`
+pub struct Binary<'a>(NonNull<llvm::object::LLVMOpaqueBinary>, PhantomData<&'a Context>);
+
+llvm_inner_impl!(Binary<'a>, llvm::object::LLVMOpaqueBinary);
+
+impl<'a> Binary<'a> {
+    /// Parse `buf` as a binary file within `context`
+    pub fn new(context: &'a Context, buf: MemoryBuffer) -> Result<Binary<'a>, Error> {
+        let mut message = std::ptr::null_mut();
+
+        let ptr = unsafe {
+            llvm::object::LLVMCreateBinary(buf.llvm_inner(), context.llvm_inner(), &mut message)
+        };
+
+        if ptr.is_null() {
+            return Err(Error::Message(Message::from_raw(message)));
+        }
+
+        // `LLVMCreateBinary` takes ownership of the buffer on success.
+        std::mem::forget(buf);
+
+        Ok(Binary(wrap_inner(ptr)?, PhantomData))
+    }
+
+    /// Open and parse the file at `path` as a binary file within `context`
+    pub fn from_file(context: &'a Context, path: impl AsRef<std::path::Path>) -> Result<Binary<'a>, Error> {
+        Self::new(context, MemoryBuffer::from_file(path)?)
+    }
+
+    /// The kind of binary this is (object file, archive, universal binary, ...)
+    pub fn kind(&self) -> BinaryType {
+        unsafe { llvm::object::LLVMBinaryGetType(self.llvm_inner()) }
+    }
+
+    /// The bytes backing this binary. `LLVMBinaryCopyMemoryBuffer` hands back a shallow view
+    /// still owned by the binary itself (LLVM documents it as illegal to free independently), so
+    /// this borrows the data rather than handing out an owning [`MemoryBuffer`]. The buffer
+    /// handle itself (not the data it points at) is disposed of here before returning.
+    pub fn data(&self) -> &[u8] {
+        unsafe {
+            let buf = llvm::object::LLVMBinaryCopyMemoryBuffer(self.llvm_inner());
+            let start = llvm::core::LLVMGetBufferStart(buf);
+            let size = llvm::core::LLVMGetBufferSize(buf);
+            llvm::core::LLVMDisposeMemoryBuffer(buf);
+            std::slice::from_raw_parts(start as *const u8, size)
+        }
+    }
+}
+
+impl<'a> Drop for Binary<'a> {
+    fn drop(&mut self) {
+        unsafe { llvm::object::LLVMDisposeBinary(self.llvm_inner()) }
+    }
+}
+
+const AR_MAGIC: &[u8] = b"!<arch>\n";
+
+/// A static archive (`.a`/`.rlib`), opened for reading its member files.
+///
+/// LLVM's public C API can recognize a buffer as an archive (via [`Binary::kind`]) but does not
+/// expose a way to walk its members, so this reads the common ar(1) layout directly off the
+/// binary's backing bytes.
+pub struct Archive<'a>(Binary<'a>);
+
+impl<'a> Archive<'a> {
+    /// Open `buf` as a static archive within `context`
+    pub fn new(context: &'a Context, buf: MemoryBuffer) -> Result<Archive<'a>, Error> {
+        let binary = Binary::new(context, buf)?;
+        Self::from_binary(binary)
+    }
+
+    /// Open the file at `path` as a static archive within `context`
+    pub fn from_file(
+        context: &'a Context,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Archive<'a>, Error> {
+        Self::new(context, MemoryBuffer::from_file(path)?)
+    }
+
+    /// Wrap an already-opened [`Binary`], failing if it is not archive-typed
+    pub fn from_binary(binary: Binary<'a>) -> Result<Archive<'a>, Error> {
+        if !matches!(binary.kind(), BinaryType::LLVMBinaryTypeArchive) {
+            return Err(Error::Custom("binary is not a static archive".into()));
+        }
+
+        Ok(Archive(binary))
+    }
+
+    /// The underlying [`Binary`] handle, e.g. to inspect it with other `Binary` accessors
+    pub fn binary(&self) -> &Binary<'a> {
+        &self.0
+    }
+
+    /// Iterate over the members of this archive
+    pub fn members(&self) -> ArchiveMemberIter<'_> {
+        parse_members(self.0.data())
+    }
+}
+
+fn parse_members(data: &[u8]) -> ArchiveMemberIter<'_> {
+    let pos = if data.starts_with(AR_MAGIC) {
+        AR_MAGIC.len()
+    } else {
+        0
+    };
+
+    ArchiveMemberIter {
+        data,
+        pos,
+        names: &[],
+    }
+}
+
+/// An iterator over the members of an [`Archive`], skipping the symbol table and GNU long-name
+/// table pseudo-members
+pub struct ArchiveMemberIter<'a> {
+    data: &'a [u8],
+    pos: usize,
+    names: &'a [u8],
+}
+
+impl<'a> Iterator for ArchiveMemberIter<'a> {
+    type Item = ArchiveMember<'a>;
+
+    fn next(&mut self) -> Option<ArchiveMember<'a>> {
+        loop {
+            // Each member is preceded by a fixed 60 byte header.
+            if self.pos + 60 > self.data.len() {
+                return None;
+            }
+
+            let header = &self.data[self.pos..self.pos + 60];
+            let name_field = std::str::from_utf8(&header[0..16]).ok()?.trim_end();
+            let size: usize = std::str::from_utf8(&header[48..58]).ok()?.trim().parse().ok()?;
+
+            let data_start = self.pos + 60;
+            let data_end = data_start + size;
+            if data_end > self.data.len() {
+                return None;
+            }
+            let data = &self.data[data_start..data_end];
+
+            // Members are padded to an even offset with a trailing newline.
+            self.pos = data_end + (size % 2);
+
+            match name_field {
+                // The symbol table, not a real member.
+                "/" | "/SYM64/" => continue,
+                // The GNU long-filename table; remember it to resolve later members' names.
+                "//" => {
+                    self.names = data;
+                    continue;
+                }
+                _ => {}
+            }
+
+            let name = match name_field.strip_prefix('/') {
+                // GNU long name: a `/<offset>` reference into the `//` table read above.
+                Some(offset) => {
+                    let offset: usize = offset.parse().ok()?;
+                    let rest = self.names.get(offset..)?;
+                    let end = rest.iter().position(|&b| b == b'\n').unwrap_or(rest.len());
+                    std::str::from_utf8(&rest[..end]).ok()?.trim_end_matches('/')
+                }
+                None => name_field.trim_end_matches('/'),
+            };
+
+            return Some(ArchiveMember { name, data });
+        }
+    }
+}
+
+/// A single member (file) within a static archive
+pub struct ArchiveMember<'a> {
+    name: &'a str,
+    data: &'a [u8],
+}
+
+impl<'a> ArchiveMember<'a> {
+    /// The member's file name within the archive
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Parse this member as a [`Binary`], e.g. to inspect the symbols of an object-file member
+    pub fn as_binary(&self, context: &'a Context) -> Result<Binary<'a>, Error> {
+        Binary::new(context, MemoryBuffer::from_slice(self.name, self.data)?)
+    }
+}
+
+impl<'a> AsRef<[u8]> for ArchiveMember<'a> {
+    fn as_ref(&self) -> &[u8] {
+        self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ar_header(name: &str, size: usize) -> [u8; 60] {
+        let mut header = [b' '; 60];
+        header[0..name.len()].copy_from_slice(name.as_bytes());
+        header[58] = b'`';
+        header[59] = b'\n';
+
+        let size = size.to_string();
+        header[48..48 + size.len()].copy_from_slice(size.as_bytes());
+
+        header
+    }
+
+    fn push_member(buf: &mut Vec<u8>, name: &str, data: &[u8]) {
+        buf.extend_from_slice(&ar_header(name, data.len()));
+        buf.extend_from_slice(data);
+        if data.len() % 2 == 1 {
+            buf.push(b'\n');
+        }
+    }
+
+    #[test]
+    fn parses_short_names() {
+        let mut buf = AR_MAGIC.to_vec();
+        push_member(&mut buf, "a.o/", b"hello");
+        push_member(&mut buf, "bb.o/", b"odd");
+
+        let members: Vec<_> = parse_members(&buf).collect();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].name(), "a.o");
+        assert_eq!(members[0].as_ref(), b"hello");
+        assert_eq!(members[1].name(), "bb.o");
+        assert_eq!(members[1].as_ref(), b"odd");
+    }
+
+    #[test]
+    fn resolves_gnu_long_names() {
+        let mut buf = AR_MAGIC.to_vec();
+        let names = b"a_very_long_member_name.o/\n";
+        push_member(&mut buf, "//", names);
+        push_member(&mut buf, "/0", b"contents");
+
+        let members: Vec<_> = parse_members(&buf).collect();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].name(), "a_very_long_member_name.o");
+        assert_eq!(members[0].as_ref(), b"contents");
+    }
+
+    #[test]
+    fn truncated_archive_ends_iteration() {
+        let mut buf = AR_MAGIC.to_vec();
+        push_member(&mut buf, "a.o/", b"hello!");
+        buf.truncate(buf.len() - 1);
+
+        let members: Vec<_> = parse_members(&buf).collect();
+        assert!(members.is_empty());
+    }
+}
@@ -0,0 +1,79 @@
+use crate::*;
+
+type DiagnosticHandler = Box<dyn FnMut(DiagnosticSeverity, Message)>;
+
+/// An LLVM context owns the types, constants, and other global state associated with the modules
+/// built against it. Most other handles in this crate borrow a `Context` for their lifetime.
+pub struct Context(NonNull<llvm::LLVMContext>, Option<Box<DiagnosticHandler>>);
+
+llvm_inner_impl!(Context, llvm::LLVMContext);
+
+impl Context {
+    /// Create a new, isolated LLVM context
+    pub fn new() -> Result<Context, Error> {
+        let ptr = unsafe { llvm::core::LLVMContextCreate() };
+
+        Ok(Context(wrap_inner(ptr)?, None))
+    }
+
+    /// Install `handler` to observe the diagnostics LLVM emits during verification, inlining, and
+    /// codegen against this context, instead of losing them.
+    ///
+    /// The handler is owned by the context and is torn down along with it.
+    pub fn set_diagnostic_handler(&mut self, handler: impl FnMut(DiagnosticSeverity, Message) + 'static) {
+        let handler: Box<DiagnosticHandler> = Box::new(Box::new(handler));
+        let context_ptr = unsafe { handler.as_ref() as *const DiagnosticHandler as *mut c_void };
+
+        unsafe {
+            llvm::core::LLVMContextSetDiagnosticHandler(
+                self.llvm_inner(),
+                Some(diagnostic_handler_trampoline),
+                context_ptr,
+            )
+        }
+
+        // Keep the handler alive for as long as the context is; LLVM only holds the raw pointer.
+        self.1 = Some(handler);
+    }
+}
+
+extern "C" fn diagnostic_handler_trampoline(
+    info: llvm::prelude::LLVMDiagnosticInfoRef,
+    handler: *mut c_void,
+) {
+    unsafe {
+        let severity = llvm::core::LLVMGetDiagInfoSeverity(info);
+        let description = Message::from_raw(llvm::core::LLVMGetDiagInfoDescription(info));
+
+        let handler = &mut *(handler as *mut DiagnosticHandler);
+        handler(severity, description);
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        unsafe { llvm::core::LLVMContextDispose(self.llvm_inner()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn diagnostic_handler_sees_parse_failure() {
+        let mut context = Context::new().unwrap();
+        let seen = Rc::new(Cell::new(false));
+        let seen_in_handler = seen.clone();
+        context.set_diagnostic_handler(move |severity, _message| {
+            assert_eq!(severity, DiagnosticSeverity::LLVMDSError);
+            seen_in_handler.set(true);
+        });
+
+        let buf = MemoryBuffer::from_slice("bad", b"not bitcode at all").unwrap();
+        assert!(Module::parse_bitcode(&context, &buf).is_err());
+        assert!(seen.get());
+    }
+}
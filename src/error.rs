@@ -0,0 +1,36 @@
+use crate::*;
+
+/// Errors that can occur while using this crate, typically surfaced from LLVM itself.
+#[derive(Debug)]
+pub enum Error {
+    /// LLVM returned a null pointer where a valid handle was expected
+    NullPointer,
+    /// A given path was not valid UTF-8
+    InvalidPath,
+    /// LLVM produced a diagnostic message describing the failure
+    Message(Message),
+    /// A failure originating in this crate rather than from an LLVM-owned diagnostic message
+    Custom(String),
+    /// An I/O error occurred while reading or writing to disk
+    Io(std::io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::NullPointer => write!(fmt, "LLVM returned a null pointer"),
+            Error::InvalidPath => write!(fmt, "path is not valid UTF-8"),
+            Error::Message(m) => write!(fmt, "{}", m),
+            Error::Custom(m) => write!(fmt, "{}", m),
+            Error::Io(e) => write!(fmt, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
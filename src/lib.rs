@@ -38,7 +38,9 @@ mod context;
 mod error;
 mod execution_engine;
 mod module;
+mod pass_builder;
 mod pass_manager;
+mod target_machine;
 mod typ;
 mod value;
 
@@ -49,16 +51,18 @@ pub(crate) use std::ptr::NonNull;
 
 pub(crate) use llvm_sys as llvm;
 
-pub use crate::attribute::Attribute;
+pub use crate::attribute::{Attribute, AttributePlace};
 pub use crate::basic_block::BasicBlock;
-pub use crate::binary::Binary;
+pub use crate::binary::{Archive, ArchiveMember, ArchiveMemberIter, Binary};
 pub use crate::builder::Builder;
 pub use crate::codegen::Codegen;
 pub use crate::context::Context;
 pub use crate::error::Error;
 pub use crate::execution_engine::ExecutionEngine;
 pub use crate::module::Module;
+pub use crate::pass_builder::PassBuilderOptions;
 pub use crate::pass_manager::PassManager;
+pub use crate::target_machine::{Target, TargetMachine};
 pub use crate::typ::{FunctionType, StructType, Type, TypeKind};
 pub use crate::value::{Const, Function, Value, ValueKind};
 
@@ -69,6 +73,9 @@ pub use llvm::{
     LLVMLinkage as Linkage, LLVMModuleFlagBehavior as ModuleFlagBehavior, LLVMOpcode as OpCode,
     LLVMRealPredicate as RealPredicate, LLVMThreadLocalMode as ThreadLocalMode,
     LLVMUnnamedAddr as UnnamedAddr, LLVMVisibility as Visibility,
+    target_machine::LLVMCodeGenFileType as FileType,
+    target_machine::LLVMCodeGenOptLevel as OptLevel, target_machine::LLVMCodeModel as CodeModel,
+    target_machine::LLVMRelocMode as RelocMode,
 };
 
 /// Allows for llama types to be converted into LLVM pointers
@@ -0,0 +1,130 @@
+use crate::*;
+
+/// An LLVM module: a single translation unit containing functions, globals, and metadata
+///
+/// Note: bitcode carrying an embedded ThinLTO per-module summary (as opposed to the plain bitcode
+/// [`Module::write_bitcode`] produces) isn't exposed here. Building the `ModuleSummaryIndex` a
+/// ThinLTO "thin buffer" needs is only reachable from `llvm::ThinLTOBitcodeWriterPass` in C++;
+/// llvm-c has no binding for it.
+pub struct Module<'a>(NonNull<llvm::LLVMModule>, PhantomData<&'a Context>);
+
+llvm_inner_impl!(Module<'a>, llvm::LLVMModule);
+
+impl<'a> Module<'a> {
+    /// Create a new, empty module named `name` within `context`
+    pub fn new(context: &'a Context, name: impl AsRef<str>) -> Result<Module<'a>, Error> {
+        let name = cstr!(name.as_ref());
+        let ptr = unsafe {
+            llvm::core::LLVMModuleCreateWithNameInContext(name.as_ptr(), context.llvm_inner())
+        };
+
+        Ok(Module(wrap_inner(ptr)?, PhantomData))
+    }
+
+    /// The module's identifier (typically its file or crate name)
+    pub fn identifier(&self) -> Result<&str, Error> {
+        let mut len = 0;
+        let ptr = unsafe { llvm::core::LLVMGetModuleIdentifier(self.llvm_inner(), &mut len) };
+        if ptr.is_null() {
+            return Err(Error::NullPointer);
+        }
+
+        let slice = unsafe { std::slice::from_raw_parts(ptr as *const u8, len) };
+        Ok(unsafe { std::str::from_utf8_unchecked(slice) })
+    }
+
+    /// Set the target triple this module was (or should be) compiled for
+    pub fn set_target_triple(&self, triple: impl AsRef<str>) {
+        let triple = cstr!(triple.as_ref());
+        unsafe { llvm::core::LLVMSetTarget(self.llvm_inner(), triple.as_ptr()) }
+    }
+
+    /// Set the data layout string this module expects, e.g. from [`TargetMachine::data_layout`](crate::TargetMachine::data_layout)
+    pub fn set_data_layout(&self, layout: impl AsRef<str>) {
+        let layout = cstr!(layout.as_ref());
+        unsafe { llvm::core::LLVMSetDataLayout(self.llvm_inner(), layout.as_ptr()) }
+    }
+
+    /// Run a textual new-pass-manager pipeline (e.g. `"default<O2>"`, `"mem2reg"`) over this
+    /// module against `tm`, using the toggles configured in `opts`
+    pub fn run_passes(
+        &self,
+        passes: impl AsRef<str>,
+        tm: &TargetMachine,
+        opts: &PassBuilderOptions,
+    ) -> Result<(), Error> {
+        let passes = cstr!(passes.as_ref());
+
+        let err = unsafe {
+            llvm::transforms::pass_builder::LLVMRunPasses(
+                self.llvm_inner(),
+                passes.as_ptr(),
+                tm.llvm_inner(),
+                opts.llvm_inner(),
+            )
+        };
+
+        if !err.is_null() {
+            let message = unsafe {
+                let msg = llvm::error::LLVMGetErrorMessage(err);
+                let message = Message::from_raw(msg);
+                llvm::error::LLVMConsumeError(err);
+                message
+            };
+            return Err(Error::Message(message));
+        }
+
+        Ok(())
+    }
+
+    /// Serialize this module to LLVM bitcode, suitable for [`Module::parse_bitcode`] or feeding
+    /// to a linker/LTO step
+    pub fn write_bitcode(&self) -> Result<MemoryBuffer, Error> {
+        let ptr = unsafe { llvm::bit_writer::LLVMWriteBitcodeToMemoryBuffer(self.llvm_inner()) };
+
+        MemoryBuffer::from_raw(ptr)
+    }
+
+    /// Deserialize a module previously produced by [`Module::write_bitcode`] into `context`
+    pub fn parse_bitcode(context: &'a Context, buf: &MemoryBuffer) -> Result<Module<'a>, Error> {
+        let mut module = std::ptr::null_mut();
+
+        let failed = unsafe {
+            llvm::bit_reader::LLVMParseBitcodeInContext2(
+                context.llvm_inner(),
+                buf.llvm_inner(),
+                &mut module,
+            ) == 1
+        };
+
+        if failed {
+            return Err(Error::Custom("failed to parse bitcode".into()));
+        }
+
+        Ok(Module(wrap_inner(module)?, PhantomData))
+    }
+}
+
+impl<'a> Drop for Module<'a> {
+    fn drop(&mut self) {
+        unsafe { llvm::core::LLVMDisposeModule(self.llvm_inner()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_bitcode() {
+        let context = Context::new().unwrap();
+        let module = Module::new(&context, "smoke").unwrap();
+        module.set_target_triple("x86_64-unknown-linux-gnu");
+        let buf = module.write_bitcode().unwrap();
+        assert!(buf.len() > 0);
+
+        let parsed = Module::parse_bitcode(&context, &buf).unwrap();
+        let triple = unsafe { std::ffi::CStr::from_ptr(llvm::core::LLVMGetTarget(parsed.llvm_inner())) };
+        assert_eq!(triple.to_str().unwrap(), "x86_64-unknown-linux-gnu");
+    }
+}
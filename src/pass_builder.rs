@@ -0,0 +1,80 @@
+use crate::*;
+
+/// Options controlling a new-pass-manager pipeline run via [`Module::run_passes`]
+pub struct PassBuilderOptions(NonNull<llvm::transforms::pass_builder::LLVMOpaquePassBuilderOptions>);
+
+llvm_inner_impl!(
+    PassBuilderOptions,
+    llvm::transforms::pass_builder::LLVMOpaquePassBuilderOptions
+);
+
+macro_rules! toggle {
+    ($name:ident, $set:ident) => {
+        #[doc = concat!("Forwards `value` to LLVM's `", stringify!($set), "`")]
+        pub fn $name(self, value: bool) -> Self {
+            unsafe {
+                llvm::transforms::pass_builder::$set(self.llvm_inner(), value as i32);
+            }
+            self
+        }
+    };
+}
+
+impl PassBuilderOptions {
+    /// Create a fresh set of pass-builder options, holding LLVM's default settings
+    pub fn new() -> Result<PassBuilderOptions, Error> {
+        let ptr = unsafe { llvm::transforms::pass_builder::LLVMCreatePassBuilderOptions() };
+
+        Ok(PassBuilderOptions(wrap_inner(ptr)?))
+    }
+
+    toggle!(set_verify_each, LLVMPassBuilderOptionsSetVerifyEach);
+    toggle!(
+        set_loop_interleaving,
+        LLVMPassBuilderOptionsSetLoopInterleaving
+    );
+    toggle!(
+        set_loop_vectorization,
+        LLVMPassBuilderOptionsSetLoopVectorization
+    );
+    toggle!(
+        set_slp_vectorization,
+        LLVMPassBuilderOptionsSetSLPVectorization
+    );
+    toggle!(set_loop_unrolling, LLVMPassBuilderOptionsSetLoopUnrolling);
+    toggle!(set_merge_functions, LLVMPassBuilderOptionsSetMergeFunctions);
+}
+
+impl Drop for PassBuilderOptions {
+    fn drop(&mut self) {
+        unsafe { llvm::transforms::pass_builder::LLVMDisposePassBuilderOptions(self.llvm_inner()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_pipeline_over_module() {
+        Target::initialize_native().unwrap();
+        let target = Target::host().unwrap();
+        let triple = Message::from_raw(unsafe { llvm::target_machine::LLVMGetDefaultTargetTriple() });
+        let tm = TargetMachine::new(
+            &target,
+            triple.as_ref(),
+            "generic",
+            "",
+            OptLevel::LLVMCodeGenLevelDefault,
+            RelocMode::LLVMRelocDefault,
+            CodeModel::LLVMCodeModelDefault,
+        )
+        .unwrap();
+
+        let context = Context::new().unwrap();
+        let module = Module::new(&context, "smoke").unwrap();
+
+        let opts = PassBuilderOptions::new().unwrap().set_verify_each(true);
+        module.run_passes("mem2reg", &tm, &opts).unwrap();
+    }
+}
@@ -0,0 +1,197 @@
+use crate::*;
+
+/// A backend registered with LLVM that can generate code for a particular architecture, e.g. the
+/// target looked up for `x86_64-unknown-linux-gnu`
+pub struct Target(NonNull<llvm::target_machine::LLVMTarget>);
+
+llvm_inner_impl!(Target, llvm::target_machine::LLVMTarget);
+
+impl Target {
+    /// Initialize the native target along with its assembly printer and parser.
+    ///
+    /// Must be called before [`Target::from_triple`]/[`Target::host`] can find anything to
+    /// construct a [`TargetMachine`] from.
+    pub fn initialize_native() -> Result<(), Error> {
+        unsafe {
+            if llvm::target::LLVM_InitializeNativeTarget() != 0 {
+                return Err(Error::Custom("failed to initialize native target".into()));
+            }
+            llvm::target::LLVM_InitializeNativeAsmPrinter();
+            llvm::target::LLVM_InitializeNativeAsmParser();
+        }
+
+        Ok(())
+    }
+
+    /// Look up the target registered for `triple`, e.g. `x86_64-unknown-linux-gnu`
+    pub fn from_triple(triple: impl AsRef<str>) -> Result<Target, Error> {
+        let triple = cstr!(triple.as_ref());
+        let mut target = std::ptr::null_mut();
+        let mut message = std::ptr::null_mut();
+
+        let ok = unsafe {
+            llvm::target_machine::LLVMGetTargetFromTriple(
+                triple.as_ptr(),
+                &mut target,
+                &mut message,
+            ) == 0
+        };
+
+        if !ok {
+            return Err(Error::Message(Message::from_raw(message)));
+        }
+
+        Ok(Target(wrap_inner(target)?))
+    }
+
+    /// Look up the target for the host machine's default triple
+    pub fn host() -> Result<Target, Error> {
+        let triple = Message::from_raw(unsafe { llvm::target_machine::LLVMGetDefaultTargetTriple() });
+
+        Self::from_triple(triple.as_ref())
+    }
+}
+
+/// A configured backend ready to lower a [`Module`] to an object file or assembly
+pub struct TargetMachine(NonNull<llvm::target_machine::LLVMOpaqueTargetMachine>);
+
+llvm_inner_impl!(TargetMachine, llvm::target_machine::LLVMOpaqueTargetMachine);
+
+impl TargetMachine {
+    /// Create a target machine for `target`, optimizing `cpu`/`features` at `level`
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        target: &Target,
+        triple: impl AsRef<str>,
+        cpu: impl AsRef<str>,
+        features: impl AsRef<str>,
+        level: OptLevel,
+        reloc: RelocMode,
+        code_model: CodeModel,
+    ) -> Result<TargetMachine, Error> {
+        let triple = cstr!(triple.as_ref());
+        let cpu = cstr!(cpu.as_ref());
+        let features = cstr!(features.as_ref());
+
+        let ptr = unsafe {
+            llvm::target_machine::LLVMCreateTargetMachine(
+                target.llvm_inner(),
+                triple.as_ptr(),
+                cpu.as_ptr(),
+                features.as_ptr(),
+                level,
+                reloc,
+                code_model,
+            )
+        };
+
+        Ok(TargetMachine(wrap_inner(ptr)?))
+    }
+
+    /// The data layout this machine expects of modules it codegens. Set this on a [`Module`]
+    /// before running codegen, since correct lowering requires the module's data layout and
+    /// triple to match the machine.
+    pub fn data_layout(&self) -> Message {
+        unsafe {
+            let layout = llvm::target_machine::LLVMCreateTargetDataLayout(self.llvm_inner());
+            let repr = llvm::target::LLVMCopyStringRepOfTargetData(layout);
+            llvm::target::LLVMDisposeTargetData(layout);
+            Message::from_raw(repr)
+        }
+    }
+
+    /// Lower `module` and write the result to `path`
+    pub fn emit_to_file(
+        &self,
+        module: &Module<'_>,
+        path: impl AsRef<std::path::Path>,
+        file_type: FileType,
+    ) -> Result<(), Error> {
+        let path = match path.as_ref().to_str() {
+            Some(p) => cstr!(p),
+            None => return Err(Error::InvalidPath),
+        };
+        let mut message = std::ptr::null_mut();
+
+        let failed = unsafe {
+            llvm::target_machine::LLVMTargetMachineEmitToFile(
+                self.llvm_inner(),
+                module.llvm_inner(),
+                path.as_ptr() as *mut c_char,
+                file_type,
+                &mut message,
+            ) == 1
+        };
+
+        if failed {
+            return Err(Error::Message(Message::from_raw(message)));
+        }
+
+        Ok(())
+    }
+
+    /// Lower `module` into an in-memory object file or assembly buffer
+    pub fn emit_to_memory_buffer(
+        &self,
+        module: &Module<'_>,
+        file_type: FileType,
+    ) -> Result<MemoryBuffer, Error> {
+        let mut buf = std::ptr::null_mut();
+        let mut message = std::ptr::null_mut();
+
+        let failed = unsafe {
+            llvm::target_machine::LLVMTargetMachineEmitToMemoryBuffer(
+                self.llvm_inner(),
+                module.llvm_inner(),
+                file_type,
+                &mut message,
+                &mut buf,
+            ) == 1
+        };
+
+        if failed {
+            return Err(Error::Message(Message::from_raw(message)));
+        }
+
+        MemoryBuffer::from_raw(buf)
+    }
+}
+
+impl Drop for TargetMachine {
+    fn drop(&mut self) {
+        unsafe { llvm::target_machine::LLVMDisposeTargetMachine(self.llvm_inner()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emits_object_code_for_host() {
+        Target::initialize_native().unwrap();
+
+        let target = Target::host().unwrap();
+        let triple = Message::from_raw(unsafe { llvm::target_machine::LLVMGetDefaultTargetTriple() });
+        let tm = TargetMachine::new(
+            &target,
+            triple.as_ref(),
+            "generic",
+            "",
+            OptLevel::LLVMCodeGenLevelDefault,
+            RelocMode::LLVMRelocDefault,
+            CodeModel::LLVMCodeModelDefault,
+        )
+        .unwrap();
+
+        assert!(!tm.data_layout().as_ref().is_empty());
+
+        let context = Context::new().unwrap();
+        let module = Module::new(&context, "smoke").unwrap();
+        module.set_target_triple(triple.as_ref());
+        module.set_data_layout(tm.data_layout().as_ref());
+
+        let obj = tm.emit_to_memory_buffer(&module, FileType::LLVMObjectFile).unwrap();
+        assert!(obj.len() > 0);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::*;
+
+pub use llvm::LLVMTypeKind as TypeKind;
+
+/// A type within a [`Context`]
+pub struct Type<'a>(NonNull<llvm::LLVMType>, PhantomData<&'a Context>);
+
+llvm_inner_impl!(Type<'a>, llvm::LLVMType);
+
+impl<'a> Type<'a> {
+    pub(crate) fn from_inner(ptr: *mut llvm::LLVMType) -> Result<Type<'a>, Error> {
+        Ok(Type(wrap_inner(ptr)?, PhantomData))
+    }
+
+    /// The integer type of the given bit width
+    pub fn int(context: &'a Context, bits: u32) -> Type<'a> {
+        let ptr = unsafe { llvm::core::LLVMIntTypeInContext(context.llvm_inner(), bits) };
+
+        Type(
+            NonNull::new(ptr).expect("LLVM returned a null integer type"),
+            PhantomData,
+        )
+    }
+
+    /// The kind of this type
+    pub fn kind(&self) -> TypeKind {
+        unsafe { llvm::core::LLVMGetTypeKind(self.llvm_inner()) }
+    }
+}
+
+/// A function signature type
+pub struct FunctionType<'a>(NonNull<llvm::LLVMType>, PhantomData<&'a Context>);
+
+llvm_inner_impl!(FunctionType<'a>, llvm::LLVMType);
+
+/// A named or anonymous aggregate type
+pub struct StructType<'a>(NonNull<llvm::LLVMType>, PhantomData<&'a Context>);
+
+llvm_inner_impl!(StructType<'a>, llvm::LLVMType);
@@ -0,0 +1,102 @@
+use crate::*;
+
+pub use llvm::LLVMValueKind as ValueKind;
+
+/// Any LLVM SSA value: an instruction, constant, function, global, or argument
+pub struct Value<'a>(NonNull<llvm::LLVMValue>, PhantomData<&'a Context>);
+
+llvm_inner_impl!(Value<'a>, llvm::LLVMValue);
+
+impl<'a> Value<'a> {
+    pub(crate) fn from_inner(ptr: *mut llvm::LLVMValue) -> Result<Value<'a>, Error> {
+        Ok(Value(wrap_inner(ptr)?, PhantomData))
+    }
+
+    /// What kind of value this is
+    pub fn kind(&self) -> ValueKind {
+        unsafe { llvm::core::LLVMGetValueKind(self.llvm_inner()) }
+    }
+}
+
+/// A compile-time constant value
+pub struct Const<'a>(Value<'a>);
+
+impl<'a> LLVMInner<llvm::LLVMValue> for Const<'a> {
+    fn llvm_inner(&self) -> *mut llvm::LLVMValue {
+        self.0.llvm_inner()
+    }
+}
+
+/// A function value
+pub struct Function<'a>(Value<'a>);
+
+impl<'a> LLVMInner<llvm::LLVMValue> for Function<'a> {
+    fn llvm_inner(&self) -> *mut llvm::LLVMValue {
+        self.0.llvm_inner()
+    }
+}
+
+impl<'a> Function<'a> {
+    pub(crate) fn from_inner(ptr: *mut llvm::LLVMValue) -> Result<Function<'a>, Error> {
+        Ok(Function(Value::from_inner(ptr)?))
+    }
+
+    /// Number of formal parameters this function takes
+    pub fn param_count(&self) -> u32 {
+        unsafe { llvm::core::LLVMCountParams(self.llvm_inner()) }
+    }
+
+    /// Attach `attr` to `place` (the return value, a specific argument, or the function itself)
+    pub fn add_attribute(&self, place: AttributePlace, attr: &Attribute<'a>) -> Result<(), Error> {
+        if let AttributePlace::Argument(i) = place {
+            if i >= self.param_count() {
+                return Err(Error::Custom("attribute argument index out of range".into()));
+            }
+        }
+
+        unsafe {
+            llvm::core::LLVMAddAttributeAtIndex(
+                self.llvm_inner(),
+                place.as_index(),
+                attr.llvm_inner(),
+            )
+        }
+
+        Ok(())
+    }
+
+    /// Remove the enum attribute identified by `kind_id` from `place`
+    pub fn remove_attribute(&self, place: AttributePlace, kind_id: u32) -> Result<(), Error> {
+        if let AttributePlace::Argument(i) = place {
+            if i >= self.param_count() {
+                return Err(Error::Custom("attribute argument index out of range".into()));
+            }
+        }
+
+        unsafe {
+            llvm::core::LLVMRemoveEnumAttributeAtIndex(self.llvm_inner(), place.as_index(), kind_id)
+        }
+
+        Ok(())
+    }
+
+    /// All attributes currently attached to `place`
+    pub fn attributes_at(&self, place: AttributePlace) -> Result<Vec<Attribute<'a>>, Error> {
+        if let AttributePlace::Argument(i) = place {
+            if i >= self.param_count() {
+                return Err(Error::Custom("attribute argument index out of range".into()));
+            }
+        }
+
+        let index = place.as_index();
+        let count =
+            unsafe { llvm::core::LLVMGetAttributeCountAtIndex(self.llvm_inner(), index) };
+
+        let mut attrs = vec![std::ptr::null_mut(); count as usize];
+        unsafe {
+            llvm::core::LLVMGetAttributesAtIndex(self.llvm_inner(), index, attrs.as_mut_ptr())
+        }
+
+        attrs.into_iter().map(Attribute::from_inner).collect()
+    }
+}